@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use crate::format::FormatKind;
+
+/// A parsed command line invocation.
+pub enum Command {
+    Record {
+        device_filter: Option<String>,
+        output: Option<PathBuf>,
+        format: FormatKind,
+    },
+    Decode {
+        path: PathBuf,
+        format: FormatKind,
+    },
+    Analyze {
+        path: PathBuf,
+        format: FormatKind,
+    },
+    Redact {
+        path: PathBuf,
+        format: FormatKind,
+    },
+}
+
+const USAGE: &str = "\
+Usage:
+    tila record [--device <name>] [--output <path>] [--format <plaintext|binary|self-describing>]
+    tila decode <path> [--format <plaintext|binary|self-describing>]
+    tila analyze <path> [--format <plaintext|binary|self-describing>]
+    tila redact <path> [--format <plaintext|binary|self-describing>]";
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Command {
+    let subcommand = args.next().unwrap_or_else(|| usage_error("missing subcommand"));
+
+    match subcommand.as_str() {
+        "record" => parse_record(args),
+        "decode" => parse_path_and_format(args, "decode", |path, format| Command::Decode { path, format }),
+        "analyze" => parse_path_and_format(args, "analyze", |path, format| Command::Analyze { path, format }),
+        "redact" => parse_path_and_format(args, "redact", |path, format| Command::Redact { path, format }),
+        other => usage_error(&format!("unknown subcommand '{other}'")),
+    }
+}
+
+fn parse_record(mut args: impl Iterator<Item = String>) -> Command {
+    let mut device_filter = None;
+    let mut output = None;
+    let mut format = FormatKind::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--device" | "-d" => device_filter = Some(expect_value(&mut args, &arg)),
+            "--output" | "-o" => output = Some(PathBuf::from(expect_value(&mut args, &arg))),
+            "--format" | "-f" => format = parse_format(&expect_value(&mut args, &arg)),
+            other => usage_error(&format!("unknown flag '{other}' for record")),
+        }
+    }
+
+    Command::Record {
+        device_filter,
+        output,
+        format,
+    }
+}
+
+fn parse_path_and_format(
+    mut args: impl Iterator<Item = String>,
+    subcommand: &str,
+    build: impl FnOnce(PathBuf, FormatKind) -> Command,
+) -> Command {
+    let mut path = None;
+    let mut format = FormatKind::default();
+
+    while let Some(arg) = args.next() {
+        if arg == "--format" || arg == "-f" {
+            format = parse_format(&expect_value(&mut args, &arg));
+        } else if arg.starts_with('-') {
+            usage_error(&format!("unknown flag '{arg}' for {subcommand}"));
+        } else if path.is_none() {
+            path = Some(PathBuf::from(arg));
+        } else {
+            usage_error(&format!("unexpected argument '{arg}' for {subcommand}"));
+        }
+    }
+
+    let path = path.unwrap_or_else(|| usage_error(&format!("{subcommand} requires a log file path")));
+
+    build(path, format)
+}
+
+fn expect_value(args: &mut impl Iterator<Item = String>, flag: &str) -> String {
+    args.next()
+        .unwrap_or_else(|| usage_error(&format!("flag '{flag}' requires a value")))
+}
+
+fn parse_format(name: &str) -> FormatKind {
+    FormatKind::parse(name).unwrap_or_else(|| usage_error(&format!("unknown format '{name}'")))
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {message}\n\n{USAGE}");
+    std::process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(strs: &[&str]) -> impl Iterator<Item = String> {
+        strs.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn record_with_no_flags() {
+        match parse_args(args(&["record"])) {
+            Command::Record {
+                device_filter,
+                output,
+                format,
+            } => {
+                assert_eq!(device_filter, None);
+                assert_eq!(output, None);
+                assert_eq!(format, FormatKind::Plaintext);
+            }
+            _ => panic!("expected Command::Record"),
+        }
+    }
+
+    #[test]
+    fn record_with_all_flags() {
+        match parse_args(args(&[
+            "record",
+            "--device",
+            "keyboard",
+            "--output",
+            "/tmp/log",
+            "--format",
+            "binary",
+        ])) {
+            Command::Record {
+                device_filter,
+                output,
+                format,
+            } => {
+                assert_eq!(device_filter.as_deref(), Some("keyboard"));
+                assert_eq!(output, Some(PathBuf::from("/tmp/log")));
+                assert_eq!(format, FormatKind::Binary);
+            }
+            _ => panic!("expected Command::Record"),
+        }
+    }
+
+    #[test]
+    fn decode_with_path_and_format() {
+        match parse_args(args(&["decode", "/tmp/log", "--format", "self-describing"])) {
+            Command::Decode { path, format } => {
+                assert_eq!(path, PathBuf::from("/tmp/log"));
+                assert_eq!(format, FormatKind::SelfDescribing);
+            }
+            _ => panic!("expected Command::Decode"),
+        }
+    }
+
+    #[test]
+    fn analyze_with_path_only() {
+        match parse_args(args(&["analyze", "/tmp/log"])) {
+            Command::Analyze { path, format } => {
+                assert_eq!(path, PathBuf::from("/tmp/log"));
+                assert_eq!(format, FormatKind::Plaintext);
+            }
+            _ => panic!("expected Command::Analyze"),
+        }
+    }
+
+    #[test]
+    fn redact_with_path_only() {
+        match parse_args(args(&["redact", "/tmp/log"])) {
+            Command::Redact { path, format } => {
+                assert_eq!(path, PathBuf::from("/tmp/log"));
+                assert_eq!(format, FormatKind::Plaintext);
+            }
+            _ => panic!("expected Command::Redact"),
+        }
+    }
+}
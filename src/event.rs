@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// A single keystroke captured from an `xinput test` stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub timestamp_micros: u128,
+    pub action: Action,
+    pub keycode: u8,
+}
+
+/// Whether a [`KeyEvent`] is the key going down or coming back up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Press,
+    Release,
+}
+
+impl Action {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::Press => "press",
+            Action::Release => "release",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "press" => Some(Action::Press),
+            "release" => Some(Action::Release),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for KeyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} key {} {}",
+            self.timestamp_micros, self.action, self.keycode
+        )
+    }
+}
@@ -0,0 +1,85 @@
+//! Raises the process's open file descriptor limit up to the hard limit.
+//! Best-effort: never lowers the limit, and silently gives up on failure or
+//! on platforms it doesn't know about.
+
+#[cfg(unix)]
+pub fn raise_fd_limit() {
+    unsafe {
+        let mut rlim = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+
+        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut rlim) != 0 {
+            return;
+        }
+
+        let target = target_limit(rlim.rlim_max);
+
+        if target <= rlim.rlim_cur {
+            return;
+        }
+
+        rlim.rlim_cur = target;
+        libc::setrlimit(libc::RLIMIT_NOFILE, &rlim);
+    }
+}
+
+#[cfg(not(unix))]
+pub fn raise_fd_limit() {}
+
+#[cfg(any(target_os = "linux", target_os = "netbsd", target_os = "openbsd"))]
+fn target_limit(rlim_max: libc::rlim_t) -> libc::rlim_t {
+    rlim_max
+}
+
+// Catch-all for other unix targets (illumos, Haiku, Android, ...): no
+// per-process ceiling to query, so just raise to the hard limit.
+#[cfg(all(
+    unix,
+    not(any(
+        target_os = "linux",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "dragonfly"
+    ))
+))]
+fn target_limit(rlim_max: libc::rlim_t) -> libc::rlim_t {
+    rlim_max
+}
+
+// On macOS, FreeBSD and DragonFly BSD `rlim_max` may come back as
+// `RLIM_INFINITY`, which isn't a limit `setrlimit` will actually honor;
+// clamp to whatever the kernel reports as the real per-process ceiling via
+// `KERN_MAXFILESPERPROC` instead. NetBSD and OpenBSD don't expose that
+// sysctl (only the unrelated system-wide `KERN_MAXFILES`), so they fall
+// back to the plain `rlim_max` path above.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "dragonfly"))]
+fn target_limit(rlim_max: libc::rlim_t) -> libc::rlim_t {
+    match query_maxfilesperproc() {
+        Some(maxfilesperproc) => rlim_max.min(maxfilesperproc),
+        None => rlim_max,
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "dragonfly"))]
+fn query_maxfilesperproc() -> Option<libc::rlim_t> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_MAXFILESPERPROC];
+    let mut value: libc::c_int = 0;
+    let mut len = std::mem::size_of::<libc::c_int>();
+
+    let result = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            &mut value as *mut libc::c_int as *mut libc::c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+
+    (result == 0 && value >= 0).then_some(value as libc::rlim_t)
+}
@@ -0,0 +1,91 @@
+use std::io::{self, BufRead, Write};
+
+use crate::event::{Action, KeyEvent};
+
+use super::Format;
+
+/// Bytes per event: a `u128` timestamp, one action byte and one keycode byte.
+const EVENT_SIZE: usize = 16 + 1 + 1;
+
+/// Fixed-width little-endian encoding, [`EVENT_SIZE`] bytes per event.
+pub struct Binary;
+
+impl Format for Binary {
+    fn write_event(&self, writer: &mut dyn Write, event: &KeyEvent) -> io::Result<()> {
+        let mut buf = [0u8; EVENT_SIZE];
+
+        buf[0..16].copy_from_slice(&event.timestamp_micros.to_le_bytes());
+        buf[16] = action_byte(event.action);
+        buf[17] = event.keycode;
+
+        writer.write_all(&buf)
+    }
+
+    fn read_events(&self, reader: &mut dyn BufRead) -> io::Result<Vec<KeyEvent>> {
+        let mut events = vec![];
+        let mut buf = [0u8; EVENT_SIZE];
+
+        loop {
+            match reader.read_exact(&mut buf) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+
+            let timestamp_micros = u128::from_le_bytes(buf[0..16].try_into().unwrap());
+            let action = action_from_byte(buf[16]);
+            let keycode = buf[17];
+
+            events.push(KeyEvent {
+                timestamp_micros,
+                action,
+                keycode,
+            });
+        }
+
+        Ok(events)
+    }
+}
+
+fn action_byte(action: Action) -> u8 {
+    match action {
+        Action::Press => 0,
+        Action::Release => 1,
+    }
+}
+
+fn action_from_byte(byte: u8) -> Action {
+    match byte {
+        0 => Action::Press,
+        _ => Action::Release,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let events = vec![
+            KeyEvent {
+                timestamp_micros: 1652024669524708,
+                action: Action::Press,
+                keycode: 36,
+            },
+            KeyEvent {
+                timestamp_micros: 1652024669600000,
+                action: Action::Release,
+                keycode: 36,
+            },
+        ];
+
+        let mut buf = vec![];
+        for event in &events {
+            Binary.write_event(&mut buf, event).unwrap();
+        }
+
+        let decoded = Binary.read_events(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, events);
+    }
+}
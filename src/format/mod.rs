@@ -0,0 +1,53 @@
+mod binary;
+mod plaintext;
+mod self_describing;
+
+use std::io::{self, BufRead, Write};
+
+pub use binary::Binary;
+pub use plaintext::Plaintext;
+pub use self_describing::SelfDescribing;
+
+use crate::event::KeyEvent;
+
+/// A log back-end: something that knows how to serialize and deserialize
+/// a stream of [`KeyEvent`]s.
+///
+/// `write_event` is called once per keystroke on the hot recording path, so
+/// implementations should avoid anything heavier than a few writes to
+/// `writer`. `read_events` is only used by `decode`/`analyze`/`redact`, so it
+/// is free to buffer the whole log in memory.
+pub trait Format {
+    fn write_event(&self, writer: &mut dyn Write, event: &KeyEvent) -> io::Result<()>;
+
+    fn read_events(&self, reader: &mut dyn BufRead) -> io::Result<Vec<KeyEvent>>;
+}
+
+/// The set of formats selectable from the CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatKind {
+    #[default]
+    Plaintext,
+    Binary,
+    SelfDescribing,
+}
+
+impl FormatKind {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "plaintext" | "text" => Some(FormatKind::Plaintext),
+            "binary" => Some(FormatKind::Binary),
+            "self-describing" | "self_describing" => Some(FormatKind::SelfDescribing),
+            _ => None,
+        }
+    }
+
+    pub fn build(self) -> Box<dyn Format> {
+        match self {
+            FormatKind::Plaintext => Box::new(Plaintext),
+            FormatKind::Binary => Box::new(Binary),
+            FormatKind::SelfDescribing => Box::new(SelfDescribing),
+        }
+    }
+}
+
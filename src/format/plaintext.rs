@@ -0,0 +1,83 @@
+use std::io::{self, BufRead, Write};
+
+use crate::event::{Action, KeyEvent};
+use crate::numtoa::{NumToA, MAX_DIGITS};
+
+use super::Format;
+
+/// The original log format: one human-readable line per event, e.g.
+/// `1652024669524708 key press 36`.
+///
+/// Kept around mostly for backwards compatibility with existing logs and
+/// for the rare case someone wants to `grep`/`tail -f` a log directly.
+pub struct Plaintext;
+
+impl Format for Plaintext {
+    fn write_event(&self, writer: &mut dyn Write, event: &KeyEvent) -> io::Result<()> {
+        let mut buf = [0u8; MAX_DIGITS];
+        let timestamp = event.timestamp_micros.numtoa(&mut buf);
+
+        writer.write_all(timestamp)?;
+        writeln!(writer, " key {} {}", event.action, event.keycode)
+    }
+
+    fn read_events(&self, reader: &mut dyn BufRead) -> io::Result<Vec<KeyEvent>> {
+        let mut events = vec![];
+
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut split_iter = line.split_whitespace();
+
+            let timestamp_micros = split_iter.next().and_then(|s| s.parse::<u128>().ok());
+            let _key_keyword = split_iter.next();
+            let action = split_iter.next().and_then(Action::from_str);
+            let keycode = split_iter.next().and_then(|s| s.parse::<u8>().ok());
+
+            if let (Some(timestamp_micros), Some(action), Some(keycode)) =
+                (timestamp_micros, action, keycode)
+            {
+                events.push(KeyEvent {
+                    timestamp_micros,
+                    action,
+                    keycode,
+                });
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let events = vec![
+            KeyEvent {
+                timestamp_micros: 1652024669524708,
+                action: Action::Press,
+                keycode: 36,
+            },
+            KeyEvent {
+                timestamp_micros: 1652024669600000,
+                action: Action::Release,
+                keycode: 36,
+            },
+        ];
+
+        let mut buf = vec![];
+        for event in &events {
+            Plaintext.write_event(&mut buf, event).unwrap();
+        }
+
+        let decoded = Plaintext.read_events(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, events);
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::{self, BufRead, Write};
+
+use crate::event::{Action, KeyEvent};
+use crate::numtoa::{NumToA, MAX_DIGITS};
+
+use super::Format;
+
+/// A key=value encoding that names every field, so a log written in one
+/// version of tila stays readable (and parseable) even as fields get added
+/// or reordered, unlike [`super::Binary`]'s fixed layout.
+pub struct SelfDescribing;
+
+impl Format for SelfDescribing {
+    fn write_event(&self, writer: &mut dyn Write, event: &KeyEvent) -> io::Result<()> {
+        let mut buf = [0u8; MAX_DIGITS];
+        let timestamp = event.timestamp_micros.numtoa(&mut buf);
+
+        writer.write_all(b"timestamp_micros=")?;
+        writer.write_all(timestamp)?;
+        writeln!(writer, " action={} keycode={}", event.action, event.keycode)
+    }
+
+    fn read_events(&self, reader: &mut dyn BufRead) -> io::Result<Vec<KeyEvent>> {
+        let mut events = vec![];
+
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut timestamp_micros = None;
+            let mut action = None;
+            let mut keycode = None;
+
+            for field in line.split_whitespace() {
+                let Some((key, value)) = field.split_once('=') else {
+                    continue;
+                };
+
+                match key {
+                    "timestamp_micros" => timestamp_micros = value.parse::<u128>().ok(),
+                    "action" => action = Action::from_str(value),
+                    "keycode" => keycode = value.parse::<u8>().ok(),
+                    _ => {}
+                }
+            }
+
+            if let (Some(timestamp_micros), Some(action), Some(keycode)) =
+                (timestamp_micros, action, keycode)
+            {
+                events.push(KeyEvent {
+                    timestamp_micros,
+                    action,
+                    keycode,
+                });
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let events = vec![
+            KeyEvent {
+                timestamp_micros: 1652024669524708,
+                action: Action::Press,
+                keycode: 36,
+            },
+            KeyEvent {
+                timestamp_micros: 1652024669600000,
+                action: Action::Release,
+                keycode: 36,
+            },
+        ];
+
+        let mut buf = vec![];
+        for event in &events {
+            SelfDescribing.write_event(&mut buf, event).unwrap();
+        }
+
+        let decoded = SelfDescribing.read_events(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, events);
+    }
+}
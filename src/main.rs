@@ -1,6 +1,5 @@
 use std::{
     env,
-    fmt::Write as _,
     io::{self, BufRead, BufReader, BufWriter, Write as _},
     path::{Path, PathBuf},
     process::{ChildStdout, Command, Stdio},
@@ -11,6 +10,16 @@ use std::{
 
 use fs_err as fs;
 
+mod cli;
+mod event;
+mod fd_limit;
+mod format;
+mod numtoa;
+mod redact;
+
+use event::{Action, KeyEvent};
+use format::{Format, FormatKind};
+
 trait ReadExt: io::Read {
     fn read_into_string(&mut self) -> io::Result<String> {
         let mut buf = String::new();
@@ -35,14 +44,28 @@ fn spawn_child(command_args: &[&str]) -> ChildStdout {
         .unwrap()
 }
 
-fn get_device_numbers(device_name: &str) -> Vec<u8> {
+/// Finds the `xinput` device ids matching `device_name` (case-insensitively),
+/// or every keyboard slave device when `device_name` is `None`.
+fn get_device_numbers(device_name: Option<&str>) -> Vec<u8> {
     let mut child_stdout = spawn_child(&["xinput", "list"]);
     let output = child_stdout.read_into_string().unwrap();
-    let (device_name, output) = (device_name.to_lowercase(), output.to_lowercase());
-
-    let matched_lines = output.lines().filter(|line| line.contains(&device_name));
+    let output = output.to_lowercase();
+
+    let matched_lines: Vec<&str> = match device_name {
+        Some(device_name) => {
+            let device_name = device_name.to_lowercase();
+            output
+                .lines()
+                .filter(|line| line.contains(&device_name))
+                .collect()
+        }
+        None => output
+            .lines()
+            .filter(|line| line.contains("slave") && line.contains("keyboard"))
+            .collect(),
+    };
 
-    parse_device_numbers(matched_lines)
+    parse_device_numbers(matched_lines.into_iter())
 }
 
 fn parse_device_numbers<'a, I>(command_output: I) -> Vec<u8>
@@ -64,7 +87,7 @@ where
     numbers
 }
 
-fn turn_on_listeners(device_numbers: &[u8]) -> mpsc::Receiver<String> {
+fn turn_on_listeners(device_numbers: &[u8]) -> mpsc::Receiver<KeyEvent> {
     let (tx, rx) = mpsc::channel();
 
     for &number in device_numbers {
@@ -78,40 +101,66 @@ fn turn_on_listeners(device_numbers: &[u8]) -> mpsc::Receiver<String> {
     rx
 }
 
-fn activate_number_listener(tx: mpsc::Sender<String>, number: u8) {
+fn activate_number_listener(tx: mpsc::Sender<KeyEvent>, number: u8) {
     let child_stdout = spawn_child(&["xinput", "test", &number.to_string()]);
     let mut reader = BufReader::new(child_stdout);
 
     let mut line = String::new();
 
     loop {
-        let micros_since_the_epoch = SystemTime::now()
+        reader.read_line(&mut line).unwrap();
+        if line.is_empty() {
+            break;
+        }
+
+        let timestamp_micros = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
             .as_micros();
 
-        write!(line, "{} ", micros_since_the_epoch).unwrap();
-
-        reader.read_line(&mut line).unwrap();
-        if line.is_empty() {
-            break;
+        if let Some(event) = parse_key_event(timestamp_micros, &line) {
+            tx.send(event).unwrap();
         }
-        tx.send(line.clone()).unwrap();
+
         line.clear();
     }
 }
 
-fn write_uncompressed(file: fs::File, receiver: mpsc::Receiver<String>) {
+// xinput prints lines such as "key press 36" while the keyboard is listened to.
+fn parse_key_event(timestamp_micros: u128, line: &str) -> Option<KeyEvent> {
+    let mut split_iter = line.split_whitespace();
+
+    let _key_keyword = split_iter.next()?; // "key"
+    let action = Action::from_str(split_iter.next()?)?;
+    let keycode = split_iter.next()?.parse::<u8>().ok()?;
+
+    Some(KeyEvent {
+        timestamp_micros,
+        action,
+        keycode,
+    })
+}
+
+fn write_uncompressed(file: fs::File, receiver: mpsc::Receiver<KeyEvent>, format: &dyn Format) {
     let mut writer = BufWriter::with_capacity(4096, file);
 
-    while let Ok(line) = receiver.recv() {
-        print!("{}", line);
-        write!(writer, "{}", line).expect("Failed to write to file");
+    while let Ok(event) = receiver.recv() {
+        println!("{}", event);
+        format
+            .write_event(&mut writer, &event)
+            .expect("Failed to write to file");
     }
 
     writer.flush().expect("Failed to flush file");
 }
 
+fn resolve_log_file(output: Option<PathBuf>) -> fs::File {
+    match output {
+        Some(path) => fs::File::create(path).expect("Could not create log file"),
+        None => create_new_log_file(),
+    }
+}
+
 fn get_log_file_path(data_dir: &Path) -> PathBuf {
     let file_count = fs::read_dir(&data_dir)
         .expect("Could not read data directory")
@@ -137,28 +186,58 @@ fn create_folder_if_not_existent(path: &Path) {
     }
 }
 
-fn run_listeners() {
-    let device_numbers = dbg!(get_device_numbers("keychron"));
+fn run_listeners(device_filter: Option<String>, output: Option<PathBuf>, format: FormatKind) {
+    let device_numbers = get_device_numbers(device_filter.as_deref());
 
     let receiver = turn_on_listeners(&device_numbers);
 
-    let log_file = create_new_log_file();
+    let log_file = resolve_log_file(output);
 
-    write_uncompressed(log_file, receiver);
+    write_uncompressed(log_file, receiver, format.build().as_ref());
 }
 
 fn main() {
-    let mut args = env::args().skip(1).collect::<Vec<_>>();
-
-    if args.is_empty() {
-        run_listeners();
-    } else {
-        decode(args.pop().unwrap());
+    fd_limit::raise_fd_limit();
+
+    let args = env::args().skip(1);
+
+    match cli::parse_args(args) {
+        cli::Command::Record {
+            device_filter,
+            output,
+            format,
+        } => run_listeners(device_filter, output, format),
+        cli::Command::Decode { path, format } => decode(path, format.build().as_ref()),
+        cli::Command::Analyze { path, format } => analyze(path, format.build().as_ref()),
+        cli::Command::Redact { path, format } => redact::redact(path, format.build().as_ref()),
     }
 }
 
-fn decode(path: impl AsRef<Path>) {
-    let contents = fs::read_to_string(path.as_ref()).expect("could not read file");
+pub(crate) fn read_log(path: impl AsRef<Path>, format: &dyn Format) -> Vec<KeyEvent> {
+    let file = fs::File::open(path.as_ref()).expect("could not read file");
+    let mut reader = BufReader::new(file);
+    format.read_events(&mut reader).expect("could not decode log")
+}
+
+fn analyze(path: impl AsRef<Path>, format: &dyn Format) {
+    let events = read_log(path, format);
+
+    let presses = events.iter().filter(|event| event.action == Action::Press).count();
+    let releases = events.iter().filter(|event| event.action == Action::Release).count();
+
+    let duration_micros = match (events.first(), events.last()) {
+        (Some(first), Some(last)) => last.timestamp_micros.saturating_sub(first.timestamp_micros),
+        _ => 0,
+    };
+
+    println!("events: {}", events.len());
+    println!("presses: {presses}");
+    println!("releases: {releases}");
+    println!("duration: {:.2}s", duration_micros as f64 / 1_000_000.0);
+}
+
+fn decode(path: impl AsRef<Path>, format: &dyn Format) {
+    let events = read_log(path, format);
 
     let keycode_translation = sugars::hmap! {
         24 => 'q',
@@ -192,18 +271,9 @@ fn decode(path: impl AsRef<Path>) {
 
     let mut results = String::new();
 
-    for line in contents.lines().map(|line| line.trim()) {
-        // 1652024669524708 key release 36
-        let mut split_iter = line.split_whitespace();
-
-        let _timestamp = split_iter.next().unwrap(); // 1652024669524708
-        let _key_keyword = split_iter.next().unwrap(); // key
-        let operation = split_iter.next().unwrap(); // "press" or "release"
-        let keycode = split_iter.next().unwrap(); // 36
-
-        if operation == "press" {
-            let keycode = keycode.parse::<u8>().expect("Could not parse keycode");
-            if let Some(ch) = keycode_translation.get(&keycode) {
+    for event in &events {
+        if event.action == Action::Press {
+            if let Some(ch) = keycode_translation.get(&event.keycode) {
                 results.push(*ch);
             }
         }
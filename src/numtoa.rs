@@ -0,0 +1,53 @@
+/// Large enough to hold every digit of a `u128::MAX` with room to spare.
+pub const MAX_DIGITS: usize = 40;
+
+/// Renders an unsigned integer straight into a caller-supplied stack buffer,
+/// skipping the allocation and the generic `Display` machinery that
+/// `write!`/`format!` go through. Meant for hot paths like writing one
+/// timestamp per keystroke.
+pub trait NumToA {
+    fn numtoa(self, buf: &mut [u8; MAX_DIGITS]) -> &[u8];
+}
+
+impl NumToA for u128 {
+    fn numtoa(mut self, buf: &mut [u8; MAX_DIGITS]) -> &[u8] {
+        let mut index = buf.len();
+
+        loop {
+            index -= 1;
+            buf[index] = (self % 10) as u8 + b'0';
+            self /= 10;
+
+            if self == 0 {
+                break;
+            }
+        }
+
+        &buf[index..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(n: u128) -> String {
+        let mut buf = [0u8; MAX_DIGITS];
+        String::from_utf8(n.numtoa(&mut buf).to_vec()).unwrap()
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(render(0), "0");
+    }
+
+    #[test]
+    fn small() {
+        assert_eq!(render(36), "36");
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(render(u128::MAX), u128::MAX.to_string());
+    }
+}
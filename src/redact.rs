@@ -0,0 +1,161 @@
+use std::{
+    env,
+    io::{self, BufWriter, Write as _},
+    path::Path,
+    process::{self, Command},
+};
+
+use fs_err as fs;
+
+use crate::event::KeyEvent;
+use crate::format::Format;
+
+/// Opens a decoded log in `$EDITOR`/`$VISUAL`, applies whatever lines the
+/// user deleted back onto the underlying [`KeyEvent`] stream, and rewrites
+/// the log with only the surviving events.
+pub fn redact(path: impl AsRef<Path>, format: &dyn Format) {
+    let path = path.as_ref();
+
+    let events = crate::read_log(path, format);
+    let original_lines: Vec<String> = events.iter().map(KeyEvent::to_string).collect();
+
+    // An empty `original_lines.join("\n") + "\n"` would still write a lone
+    // "\n", which `.lines()` reads back as one blank line instead of zero.
+    let scratch_contents = if original_lines.is_empty() {
+        String::new()
+    } else {
+        original_lines.join("\n") + "\n"
+    };
+
+    let scratch_path = env::temp_dir().join(format!("tila-redact-{}.txt", process::id()));
+    fs::write(&scratch_path, scratch_contents).expect("Could not write scratch file");
+
+    if let Err(err) = spawn_editor(&scratch_path) {
+        eprintln!("error: could not run editor: {err}");
+        let _ = fs::remove_file(&scratch_path);
+        return;
+    }
+
+    let edited = fs::read_to_string(&scratch_path).expect("Could not read back scratch file");
+    let _ = fs::remove_file(&scratch_path);
+
+    let edited_lines: Vec<&str> = edited.lines().collect();
+
+    let Some(retained_indices) = match_retained_lines(&original_lines, &edited_lines) else {
+        eprintln!(
+            "error: edited log no longer maps line-for-line to the original (lines were added, \
+             reordered, or changed instead of just deleted); refusing to write back"
+        );
+        return;
+    };
+
+    let removed = events.len() - retained_indices.len();
+    let retained_events: Vec<KeyEvent> = retained_indices.into_iter().map(|index| events[index]).collect();
+
+    // Write to a temp file in the same directory and rename it over `path`
+    // only once the write succeeds, so a failure mid-write can't truncate
+    // the original log and lose the events we meant to keep.
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path.file_name().expect("log path must have a file name");
+    let tmp_path = parent.join(format!("{}.tmp-{}", file_name.to_string_lossy(), process::id()));
+
+    let file = fs::File::create(&tmp_path).expect("Could not create temp file for rewrite");
+    let mut writer = BufWriter::with_capacity(4096, file);
+
+    for event in &retained_events {
+        format
+            .write_event(&mut writer, event)
+            .expect("Failed to write to temp file");
+    }
+
+    writer.flush().expect("Failed to flush temp file");
+    drop(writer);
+
+    fs::rename(&tmp_path, path).expect("Could not replace log file with redacted version");
+
+    println!("Removed {removed} event(s), kept {}", retained_events.len());
+}
+
+fn spawn_editor(path: &Path) -> io::Result<()> {
+    let editor = env::var("EDITOR")
+        .or_else(|_| env::var("VISUAL"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // `$EDITOR`/`$VISUAL` commonly carry flags (e.g. "code --wait", "vim -u
+    // NONE"), so the first whitespace-separated token is the program and the
+    // rest are its arguments.
+    let mut tokens = editor.split_whitespace();
+    let program = tokens.next().unwrap_or("vi");
+
+    let status = Command::new(program).args(tokens).arg(path).status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("editor exited with {status}")));
+    }
+
+    Ok(())
+}
+
+/// Matches each line of the edited buffer against the first equal line among
+/// the not-yet-consumed lines of the original, in order. This succeeds only when `edited` is an
+/// in-order subsequence of `original`, i.e. the user only deleted lines —
+/// any insertion, reorder, or edited line content fails the match.
+fn match_retained_lines(original: &[String], edited: &[&str]) -> Option<Vec<usize>> {
+    let mut retained_indices = Vec::with_capacity(edited.len());
+    let mut search_from = 0;
+
+    for &edited_line in edited {
+        let position = original[search_from..]
+            .iter()
+            .position(|line| line == edited_line)?;
+        let index = search_from + position;
+        retained_indices.push(index);
+        search_from = index + 1;
+    }
+
+    Some(retained_indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(strs: &[&str]) -> Vec<String> {
+        strs.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn identity_keeps_every_line() {
+        let original = lines(&["a", "b", "c"]);
+        let edited = ["a", "b", "c"];
+        assert_eq!(match_retained_lines(&original, &edited), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn deletion_keeps_remaining_indices() {
+        let original = lines(&["a", "b", "c"]);
+        let edited = ["a", "c"];
+        assert_eq!(match_retained_lines(&original, &edited), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn insertion_is_rejected() {
+        let original = lines(&["a", "b"]);
+        let edited = ["a", "x", "b"];
+        assert_eq!(match_retained_lines(&original, &edited), None);
+    }
+
+    #[test]
+    fn reorder_is_rejected() {
+        let original = lines(&["a", "b"]);
+        let edited = ["b", "a"];
+        assert_eq!(match_retained_lines(&original, &edited), None);
+    }
+
+    #[test]
+    fn empty_input_matches_trivially() {
+        let original: Vec<String> = vec![];
+        let edited: [&str; 0] = [];
+        assert_eq!(match_retained_lines(&original, &edited), Some(vec![]));
+    }
+}